@@ -1,6 +1,6 @@
 use std::rc::Rc;
 use yew::prelude::*;
-use yew_virtual_scroller::VirtualScroller;
+use yew_virtual_scroller::{Items, VirtualScroller};
 
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
@@ -28,7 +28,7 @@ impl Component for Example {
     }
 
     fn view(&self) -> Html {
-        let items = Rc::clone(&self.items);
+        let items = Items::Vec(Rc::clone(&self.items));
         html! {
             <div>
                 <style>{"
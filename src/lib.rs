@@ -21,7 +21,7 @@
 //!
 //! fn view(&self) -> yew::Html {
 //!     // Items is wrapped with an Rc to avoid cloning large lists.
-//!     let items = Rc::clone(&self.items);
+//!     let items = Items::Vec(Rc::clone(&self.items));
 //!     html! {
 //!         <div>
 //!             <style>{"
@@ -60,7 +60,7 @@
 
 use std::{
     cmp::{max, min},
-    fmt::Debug,
+    fmt::{self, Debug},
     ops::Range,
     rc::Rc,
 };
@@ -70,6 +70,105 @@ use yew_component_size::{ComponentSize, ComponentSizeObserver};
 
 const WINDOW_STYLES: &str = "will-change:transform;";
 
+/// Source of the items rendered by [`VirtualScroller`].
+///
+/// Either a fully materialized list, or an on-demand generator that produces an item for a given
+/// index -- useful for effectively infinite or backend-backed lists, where only the indices in
+/// `visible_range` are ever actually produced.
+pub enum Items<T> {
+    /// A fully materialized list, wrapped in an `Rc` as the assumption is the list will be large
+    /// and so cloning it would be expensive.
+    Vec(Rc<Vec<T>>),
+    /// An on-demand source of `count` items, generated by index.
+    Gen {
+        /// Total number of items available from `generator`.
+        count: usize,
+        /// Produces the item at a given index. Only ever called for indices within the current
+        /// `visible_range`.
+        generator: Rc<dyn Fn(usize) -> T>,
+    },
+}
+
+impl<T> Items<T> {
+    /// Total number of items.
+    pub fn len(&self) -> usize {
+        match self {
+            Items::Vec(items) => items.len(),
+            Items::Gen { count, .. } => *count,
+        }
+    }
+
+    /// Whether there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Items<T>
+where
+    T: Clone,
+{
+    /// Retrieves the item at `index`, cloning it out of the list or generating it on demand.
+    pub fn get(&self, index: usize) -> T {
+        match self {
+            Items::Vec(items) => items[index].clone(),
+            Items::Gen { generator, .. } => generator(index),
+        }
+    }
+}
+
+impl<T> Clone for Items<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Items::Vec(items) => Items::Vec(Rc::clone(items)),
+            Items::Gen { count, generator } => Items::Gen {
+                count: *count,
+                generator: Rc::clone(generator),
+            },
+        }
+    }
+}
+
+impl<T> PartialEq for Items<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Items::Vec(a), Items::Vec(b)) => Rc::ptr_eq(a, b) || a == b,
+            (
+                Items::Gen {
+                    count: count_a,
+                    generator: gen_a,
+                },
+                Items::Gen {
+                    count: count_b,
+                    generator: gen_b,
+                },
+            ) => count_a == count_b && Rc::ptr_eq(gen_a, gen_b),
+            _ => false,
+        }
+    }
+}
+
+impl<T> Debug for Items<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Items::Vec(items) => f.debug_tuple("Items::Vec").field(items).finish(),
+            Items::Gen { count, .. } => f.debug_struct("Items::Gen").field("count", count).finish(),
+        }
+    }
+}
+
+impl<T> From<Rc<Vec<T>>> for Items<T> {
+    fn from(items: Rc<Vec<T>>) -> Self {
+        Items::Vec(items)
+    }
+}
+
 /// Yew component for virtual scrolling / scroll windowing
 ///
 /// See the crate documentation for an example and more information.
@@ -82,8 +181,197 @@ where
 
     link: yew::ComponentLink<Self>,
     viewport_ref: NodeRef,
-    viewport_height: f64,
+    viewport_extent: f64,
     content_window: Option<ContentWindow>,
+
+    /// Per-item measured heights and their cumulative offsets, used in `variable_height` mode.
+    offsets: Offsets,
+}
+
+/// Tracks, for `variable_height` mode, the measured height of each item and the cumulative
+/// top-position ("offset") derived from them. `top(i)` is the top of item `i`, and `total()`
+/// is the top of one-past-the-last item (i.e. the total content extent). Unmeasured items
+/// contribute a caller-supplied `prior` estimate wherever a height is needed.
+///
+/// This is kept free of any DOM/Yew dependency so the offset math can be unit tested directly.
+#[derive(Debug, Default)]
+struct Offsets {
+    measured: Vec<Option<f64>>,
+    cumulative: Vec<f64>,
+}
+
+impl Offsets {
+    /// Rebuilds `cumulative` for `len` items, estimating unmeasured items at `prior`. Keeps any
+    /// previously measured heights for items that still exist, truncating/extending as needed.
+    fn rebuild(&mut self, len: usize, prior: f64) {
+        self.measured.resize(len, None);
+
+        let mut cumulative = Vec::with_capacity(len + 1);
+        cumulative.push(0.0);
+        for height in &self.measured {
+            let top = *cumulative.last().unwrap();
+            cumulative.push(top + height.unwrap_or(prior));
+        }
+        self.cumulative = cumulative;
+    }
+
+    /// Number of items tracked.
+    fn len(&self) -> usize {
+        self.measured.len()
+    }
+
+    /// Top position of item `index`. Panics if `index` is out of bounds.
+    fn top(&self, index: usize) -> f64 {
+        self.cumulative[index]
+    }
+
+    /// Top position of item `index`, or `None` if `index` is out of bounds.
+    fn top_checked(&self, index: usize) -> Option<f64> {
+        self.cumulative.get(index).copied()
+    }
+
+    /// Total content extent, i.e. the top of one-past-the-last item.
+    fn total(&self) -> f64 {
+        *self.cumulative.last().unwrap_or(&0.0)
+    }
+
+    /// Measured height of item `index`, if it has been observed yet.
+    fn measured_height(&self, index: usize) -> Option<f64> {
+        self.measured.get(index).copied().flatten()
+    }
+
+    /// Finds the index of the item whose top is at or just before `scroll_offset`, via binary
+    /// search over the cumulative offsets.
+    fn find_start_node(&self, scroll_offset: f64) -> usize {
+        let starts = &self.cumulative[..self.cumulative.len() - 1];
+        match starts.binary_search_by(|offset| offset.partial_cmp(&scroll_offset).unwrap()) {
+            Ok(i) => i,
+            Err(i) => i.saturating_sub(1),
+        }
+    }
+
+    /// Records a freshly observed height for item `index`, patching the measured height and
+    /// re-propagating the delta into the cumulative suffix. Returns the delta applied.
+    fn patch(&mut self, index: usize, height: f64, prior: f64) -> f64 {
+        let old = self.measured[index].unwrap_or(prior);
+        let delta = height - old;
+        if delta.abs() > f64::EPSILON {
+            self.measured[index] = Some(height);
+            for offset in &mut self.cumulative[index + 1..] {
+                *offset += delta;
+            }
+        }
+        delta
+    }
+}
+
+#[cfg(test)]
+mod offsets_tests {
+    use super::Offsets;
+
+    fn offsets_of(heights: &[f64]) -> Offsets {
+        let mut offsets = Offsets::default();
+        offsets.rebuild(heights.len(), 0.0);
+        for (i, &height) in heights.iter().enumerate() {
+            offsets.patch(i, height, 0.0);
+        }
+        offsets
+    }
+
+    #[test]
+    fn rebuild_estimates_unmeasured_items_at_prior() {
+        let mut offsets = Offsets::default();
+        offsets.rebuild(3, 10.0);
+        assert_eq!(offsets.top(0), 0.0);
+        assert_eq!(offsets.top(1), 10.0);
+        assert_eq!(offsets.top(2), 20.0);
+        assert_eq!(offsets.total(), 30.0);
+    }
+
+    #[test]
+    fn rebuild_keeps_previously_measured_heights() {
+        let mut offsets = Offsets::default();
+        offsets.rebuild(2, 10.0);
+        offsets.patch(0, 40.0, 10.0);
+        offsets.rebuild(3, 10.0);
+        assert_eq!(offsets.top(0), 0.0);
+        assert_eq!(offsets.top(1), 40.0);
+        assert_eq!(offsets.top(2), 50.0);
+        assert_eq!(offsets.total(), 60.0);
+    }
+
+    #[test]
+    fn find_start_node_exact_hit() {
+        let offsets = offsets_of(&[10.0, 20.0, 30.0]);
+        // tops are 0, 10, 30; an exact hit on a top returns that item.
+        assert_eq!(offsets.find_start_node(10.0), 1);
+    }
+
+    #[test]
+    fn find_start_node_between_offsets() {
+        let offsets = offsets_of(&[10.0, 20.0, 30.0]);
+        // 15 falls strictly between item 1's top (10) and item 2's top (30).
+        assert_eq!(offsets.find_start_node(15.0), 1);
+    }
+
+    #[test]
+    fn find_start_node_before_first_offset() {
+        let offsets = offsets_of(&[10.0, 20.0, 30.0]);
+        assert_eq!(offsets.find_start_node(-5.0), 0);
+    }
+
+    #[test]
+    fn find_start_node_past_end_clamps_to_last_item() {
+        let offsets = offsets_of(&[10.0, 20.0, 30.0]);
+        assert_eq!(offsets.find_start_node(1000.0), 2);
+    }
+
+    #[test]
+    fn find_start_node_empty_is_zero() {
+        let mut offsets = Offsets::default();
+        offsets.rebuild(0, 10.0);
+        assert_eq!(offsets.find_start_node(0.0), 0);
+    }
+
+    #[test]
+    fn patch_propagates_delta_into_suffix_only() {
+        let mut offsets = Offsets::default();
+        offsets.rebuild(4, 10.0);
+        // tops start at 0, 10, 20, 30; total 40.
+        let delta = offsets.patch(1, 25.0, 10.0);
+        assert_eq!(delta, 15.0);
+        assert_eq!(
+            offsets.top(0),
+            0.0,
+            "items before the patched index are untouched"
+        );
+        assert_eq!(
+            offsets.top(1),
+            10.0,
+            "the patched item's own top is unaffected"
+        );
+        assert_eq!(
+            offsets.top(2),
+            35.0,
+            "items after the patched index shift by the delta"
+        );
+        assert_eq!(offsets.top(3), 45.0);
+        assert_eq!(offsets.total(), 55.0);
+        assert_eq!(offsets.measured_height(1), Some(25.0));
+    }
+
+    #[test]
+    fn patch_is_a_no_op_when_height_is_unchanged() {
+        let mut offsets = Offsets::default();
+        offsets.rebuild(2, 10.0);
+        offsets.patch(0, 10.0, 10.0);
+        assert_eq!(
+            offsets.measured_height(0),
+            None,
+            "equal height shouldn't mark it as measured"
+        );
+        assert_eq!(offsets.top(1), 10.0);
+    }
 }
 
 /// VirtualScroller properties
@@ -92,23 +380,112 @@ pub struct Props<T>
 where
     T: Into<yew::Html> + Clone + PartialEq + Debug + 'static,
 {
-    /// Full list of items. This is within an Rc as the assumption is the list will be large
-    /// and so cloning it would be expensive.
-    pub items: Rc<Vec<T>>,
+    /// Source of the items to render, either a fully materialized `Rc<Vec<T>>` or a lazy
+    /// [`Items::Gen`] generator. See [`Items`].
+    pub items: Items<T>,
 
-    /// Height of each item in pixels.
+    /// Height of each item in pixels, or width when `orientation` is `Horizontal`.
+    ///
+    /// When `variable_height` is enabled this is only used as the default estimate for items
+    /// that haven't been measured yet -- see [`Props::prior_height`].
     pub row_height: f64,
 
+    /// Axis to window and scroll along. Defaults to `Orientation::Vertical`.
+    #[prop_or_default]
+    pub orientation: Orientation,
+
+    /// Enables variable-height mode: rather than assuming every item is exactly `row_height`
+    /// tall, each rendered item is measured with a `ResizeObserver` and the window is positioned
+    /// from those measurements, falling back to an estimate for items that haven't been
+    /// rendered yet. Off by default, since it costs an extra observer per visible row.
+    #[prop_or_default]
+    pub variable_height: bool,
+
+    /// Estimated height of an item before it has been measured, used only when
+    /// `variable_height` is enabled. Defaults to `row_height`.
+    #[prop_or_default]
+    pub prior_height: Option<f64>,
+
+    /// Number of extra items to render above and below the visible window, to reduce blank rows
+    /// flashing in while fast scrolling catches up. Defaults to `0`.
+    #[prop_or_default]
+    pub overscan: usize,
+
+    /// Invoked once after the scroller has mounted, handing the parent a [`ScrollerHandle`] it
+    /// can use to imperatively [`ScrollerHandle::scroll_to_index`] the viewport, e.g. to jump to
+    /// a selected item.
+    #[prop_or_default]
+    pub handle: Option<yew::Callback<ScrollerHandle<T>>>,
+
     /// Class(es) to apply to the root container
     #[prop_or_default]
     pub class: Classes,
 }
 
+/// Axis that a [`VirtualScroller`] windows and scrolls along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Items stack top-to-bottom and the list scrolls vertically. The default.
+    Vertical,
+    /// Items sit left-to-right and the list scrolls horizontally.
+    Horizontal,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Vertical
+    }
+}
+
+/// Alignment of a scrolled-to item within the viewport, used with
+/// [`ScrollerHandle::scroll_to_index`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// Aligns the item's top edge with the top of the viewport.
+    Start,
+    /// Centers the item within the viewport.
+    Center,
+    /// Aligns the item's bottom edge with the bottom of the viewport.
+    End,
+}
+
+/// An imperative handle to a mounted [`VirtualScroller`], handed to parents via
+/// [`Props::handle`].
+pub struct ScrollerHandle<T>
+where
+    T: Into<yew::Html> + Clone + PartialEq + Debug + 'static,
+{
+    link: yew::ComponentLink<VirtualScroller<T>>,
+}
+
+impl<T> ScrollerHandle<T>
+where
+    T: Into<yew::Html> + Clone + PartialEq + Debug + 'static,
+{
+    /// Scrolls the viewport so that the item at `index` is positioned according to `align`.
+    pub fn scroll_to_index(&self, index: usize, align: Alignment) {
+        self.link.send_message(Msg::ScrollToIndex(index, align));
+    }
+}
+
+impl<T> Clone for ScrollerHandle<T>
+where
+    T: Into<yew::Html> + Clone + PartialEq + Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            link: self.link.clone(),
+        }
+    }
+}
+
 #[doc(hidden)]
 pub enum Msg {
     CalculateViewport,
-    UpdateViewportHeight(f64),
+    UpdateViewportExtent(f64),
     CalculateWindowContent,
+    UpdateMeasuredHeight(usize, f64),
+    ScrollToIndex(usize, Alignment),
 }
 
 struct ContentWindow {
@@ -116,6 +493,69 @@ struct ContentWindow {
     visible_range: Range<usize>,
 }
 
+impl<T> VirtualScroller<T>
+where
+    T: Into<yew::Html> + Clone + PartialEq + Debug + 'static,
+{
+    fn prior_height(&self) -> f64 {
+        self.props.prior_height.unwrap_or(self.props.row_height)
+    }
+
+    /// Fully rebuilds the offsets from scratch, e.g. after the item count changes. Any
+    /// previously measured heights for items that still exist are kept.
+    fn rebuild_offsets(&mut self) {
+        let len = self.props.items.len();
+        let prior = self.prior_height();
+        self.offsets.rebuild(len, prior);
+    }
+
+    /// Finds the index of the item whose offset is at or just before `scroll_top`.
+    fn find_start_node(&self, scroll_top: f64) -> usize {
+        self.offsets.find_start_node(scroll_top)
+    }
+
+    /// Records a freshly observed height for item `index`, patching the measured height and
+    /// re-propagating the delta into the suffix of the offsets. Returns the delta applied.
+    fn patch_measured_height(&mut self, index: usize, height: f64) -> f64 {
+        let prior = self.prior_height();
+        self.offsets.patch(index, height, prior)
+    }
+
+    fn total_content_height(&self) -> f64 {
+        if self.props.variable_height {
+            self.offsets.total()
+        } else {
+            (self.props.items.len() as f64) * self.props.row_height
+        }
+    }
+
+    /// Size of `viewport` along the windowed axis: `client_height` when vertical,
+    /// `client_width` when horizontal.
+    fn client_extent(&self, viewport: &Element) -> f64 {
+        (match self.props.orientation {
+            Orientation::Vertical => viewport.client_height(),
+            Orientation::Horizontal => viewport.client_width(),
+        }) as f64
+    }
+
+    /// Scroll position of `viewport` along the windowed axis: `scroll_top` when vertical,
+    /// `scroll_left` when horizontal.
+    fn scroll_offset(&self, viewport: &Element) -> f64 {
+        (match self.props.orientation {
+            Orientation::Vertical => viewport.scroll_top(),
+            Orientation::Horizontal => viewport.scroll_left(),
+        }) as f64
+    }
+
+    /// Sets the scroll position of `viewport` along the windowed axis.
+    fn set_scroll_offset(&self, viewport: &Element, value: f64) {
+        match self.props.orientation {
+            Orientation::Vertical => viewport.set_scroll_top(value as i32),
+            Orientation::Horizontal => viewport.set_scroll_left(value as i32),
+        }
+    }
+}
+
 impl<T> Component for VirtualScroller<T>
 where
     T: Into<yew::Html> + Clone + PartialEq + Debug + 'static,
@@ -125,55 +565,135 @@ where
     type Properties = Props<T>;
 
     fn create(props: Self::Properties, link: yew::ComponentLink<Self>) -> Self {
-        Self {
+        let mut this = Self {
             props,
             link,
             viewport_ref: Default::default(),
-            viewport_height: 0f64,
+            viewport_extent: 0f64,
             content_window: None,
+            offsets: Offsets::default(),
+        };
+        if this.props.variable_height {
+            this.rebuild_offsets();
         }
+        this
     }
 
     fn update(&mut self, msg: Self::Message) -> yew::ShouldRender {
         match msg {
             Msg::CalculateViewport => {
                 let viewport = self.viewport_ref.cast::<Element>().unwrap();
-                self.viewport_height = viewport.client_height() as f64;
+                self.viewport_extent = self.client_extent(&viewport);
                 true
             }
-            Msg::UpdateViewportHeight(height) => {
-                self.viewport_height = height;
+            Msg::UpdateViewportExtent(extent) => {
+                self.viewport_extent = extent;
                 true
             }
             Msg::CalculateWindowContent => {
-                let node_padding: usize = 0;
+                let node_padding: usize = self.props.overscan;
                 let viewport = self.viewport_ref.cast::<Element>().unwrap();
-                let scroll_top = viewport.scroll_top() as f64;
-                let start_node = max(
-                    0,
-                    ((scroll_top / self.props.row_height).floor() as isize)
-                        - (node_padding as isize),
-                ) as usize;
-                let total_visible = min(
-                    ((self.viewport_height / self.props.row_height).ceil()) as usize
-                        + 2 * node_padding,
-                    self.props.items.len() - start_node,
-                );
-                let start_y = (start_node as f64) * self.props.row_height;
-                let end_node = start_node + total_visible;
+                let scroll_top = self.scroll_offset(&viewport);
+
+                let (start_node, start_y, end_node) = if self.props.variable_height {
+                    // Find the true (unpadded) window first, then pad symmetrically -- padding
+                    // `start_node` before filling would eat into the fill budget and starve the
+                    // bottom overscan.
+                    let true_start = self.find_start_node(scroll_top);
+                    let target = scroll_top + self.viewport_extent;
+                    let mut end_node = true_start;
+                    while end_node < self.props.items.len() && self.offsets.top(end_node) < target {
+                        end_node += 1;
+                    }
+
+                    let start_node =
+                        max(0, (true_start as isize) - (node_padding as isize)) as usize;
+                    let start_y = self.offsets.top(start_node);
+                    let end_node = min(end_node + node_padding, self.props.items.len());
+
+                    (start_node, start_y, end_node)
+                } else {
+                    let start_node = max(
+                        0,
+                        ((scroll_top / self.props.row_height).floor() as isize)
+                            - (node_padding as isize),
+                    ) as usize;
+                    let total_visible = min(
+                        ((self.viewport_extent / self.props.row_height).ceil()) as usize
+                            + 2 * node_padding,
+                        self.props.items.len() - start_node,
+                    );
+                    let start_y = (start_node as f64) * self.props.row_height;
+                    let end_node = start_node + total_visible;
+
+                    (start_node, start_y, end_node)
+                };
+
                 self.content_window = Some(ContentWindow {
                     start_y,
                     visible_range: start_node..end_node,
                 });
                 true
             }
+            Msg::UpdateMeasuredHeight(index, height) => {
+                if !self.props.variable_height || index >= self.offsets.len() {
+                    return false;
+                }
+
+                let viewport = self.viewport_ref.cast::<Element>().unwrap();
+                let scroll_top = self.scroll_offset(&viewport);
+                let anchor_node = self.find_start_node(scroll_top);
+                let anchor_offset = scroll_top - self.offsets.top(anchor_node);
+
+                let delta = self.patch_measured_height(index, height);
+                if delta != 0.0 && index <= anchor_node {
+                    let new_scroll_top = self.offsets.top(anchor_node) + anchor_offset;
+                    self.set_scroll_offset(&viewport, new_scroll_top);
+                }
+
+                if delta != 0.0 {
+                    self.link.send_message(Msg::CalculateWindowContent);
+                }
+                false
+            }
+            Msg::ScrollToIndex(index, align) => {
+                let viewport = self.viewport_ref.cast::<Element>().unwrap();
+                let item_top = if self.props.variable_height {
+                    self.offsets.top_checked(index).unwrap_or(0.0)
+                } else {
+                    (index as f64) * self.props.row_height
+                };
+                let item_height = if self.props.variable_height {
+                    self.offsets
+                        .measured_height(index)
+                        .unwrap_or_else(|| self.prior_height())
+                } else {
+                    self.props.row_height
+                };
+
+                let scroll_top = match align {
+                    Alignment::Start => item_top,
+                    Alignment::Center => item_top - (self.viewport_extent - item_height) / 2.0,
+                    Alignment::End => item_top - (self.viewport_extent - item_height),
+                };
+                self.set_scroll_offset(&viewport, scroll_top.max(0.0).round());
+
+                self.link.send_message(Msg::CalculateWindowContent);
+                false
+            }
         }
     }
 
     fn change(&mut self, props: Self::Properties) -> yew::ShouldRender {
         if self.props != props {
             let should_rerender = self.props.class != props.class;
+            let old_len = self.props.items.len();
             self.props = props;
+
+            if self.props.variable_height && old_len != self.props.items.len() {
+                self.rebuild_offsets();
+            }
+
             self.link.send_message(Msg::CalculateWindowContent);
             should_rerender
         } else {
@@ -182,22 +702,72 @@ where
     }
 
     fn view(&self) -> yew::Html {
-        let total_content_height = (self.props.items.len() as f64) * self.props.row_height;
-        let content_style = format!("height: {}px", total_content_height);
-
-        let (window_style, windowed_items) = match &self.content_window {
-            Some(cw) => (
-                format!("{}transform: translateY({}px);", WINDOW_STYLES, cw.start_y),
-                (&self.props.items[cw.visible_range.clone()]).into(),
-            ),
-            None => (WINDOW_STYLES.to_string(), vec![]),
+        let orientation = self.props.orientation;
+        let total_content_height = self.total_content_height();
+        let content_style = match orientation {
+            Orientation::Vertical => format!("height: {}px", total_content_height),
+            Orientation::Horizontal => format!("width: {}px", total_content_height),
+        };
+
+        // In horizontal mode the window's children need to flow left-to-right instead of the
+        // block default of stacking vertically.
+        let layout_style = match orientation {
+            Orientation::Vertical => "",
+            Orientation::Horizontal => "display:flex;",
+        };
+
+        let (window_style, visible_range) = match &self.content_window {
+            Some(cw) => {
+                let transform = match orientation {
+                    Orientation::Vertical => format!("translateY({}px)", cw.start_y),
+                    Orientation::Horizontal => format!("translateX({}px)", cw.start_y),
+                };
+                (
+                    format!("{}{}transform: {};", WINDOW_STYLES, layout_style, transform),
+                    cw.visible_range.clone(),
+                )
+            }
+            None => (format!("{}{}", WINDOW_STYLES, layout_style), 0..0),
         };
-        let items = windowed_items.into_iter().map(|item| item.into());
+
+        // In horizontal mode the wrapper has to shrink-wrap the item so the ResizeObserver
+        // measures the item's width rather than the full cross-axis extent of the window.
+        let wrapper_style = match orientation {
+            Orientation::Vertical => "position: relative;",
+            Orientation::Horizontal => "position: relative; display: inline-block;",
+        }
+        .to_string();
+
+        let variable_height = self.props.variable_height;
+        let items = visible_range.map(|index| {
+            let item: yew::Html = self.props.items.get(index).into();
+            if variable_height {
+                let onsize = self.link.callback(move |rect: ComponentSize| {
+                    let extent = match orientation {
+                        Orientation::Vertical => rect.height,
+                        Orientation::Horizontal => rect.width,
+                    };
+                    Msg::UpdateMeasuredHeight(index, extent)
+                });
+                html! {
+                    <div style=wrapper_style.clone()>
+                        {item}
+                        <ComponentSizeObserver onsize=onsize />
+                    </div>
+                }
+            } else {
+                item
+            }
+        });
 
         let onscroll = self.link.callback(|_| Msg::CalculateWindowContent);
-        let onsize = self.link.batch_callback(|rect: ComponentSize| {
+        let onsize = self.link.batch_callback(move |rect: ComponentSize| {
+            let extent = match orientation {
+                Orientation::Vertical => rect.height,
+                Orientation::Horizontal => rect.width,
+            };
             vec![
-                Msg::UpdateViewportHeight(rect.height),
+                Msg::UpdateViewportExtent(extent),
                 Msg::CalculateWindowContent,
             ]
         });
@@ -216,6 +786,11 @@ where
 
     fn rendered(&mut self, first_render: bool) {
         if first_render {
+            if let Some(handle_cb) = &self.props.handle {
+                handle_cb.emit(ScrollerHandle {
+                    link: self.link.clone(),
+                });
+            }
             self.link
                 .send_message_batch(vec![Msg::CalculateViewport, Msg::CalculateWindowContent]);
         }